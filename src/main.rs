@@ -3,22 +3,75 @@
 // modules ...
 use ::iced::theme::Theme;
 use iced::alignment::{Horizontal, Vertical};
-use iced::widget::{button, container, text, Button, Column, Container, Row, TextInput};
+use iced::widget::{button, container, radio, text, Button, Column, Container, Row, TextInput};
 use iced::{
-    Alignment, Background, Border, Element, Length, Padding, Sandbox, Settings, Shadow, Vector,
+    executor, Alignment, Application, Background, Border, Command, Element, Length, Padding,
+    Settings, Shadow, Subscription, Vector,
 };
+use serde::Deserialize;
+
+// the login endpoint, overridable so this doesn't have to be a hardcoded prod url ...
+const DEFAULT_LOGIN_ENDPOINT: &str = "http://localhost:8080/api/login";
+
+// default character used to echo each keystroke typed into a password field, overridable
+// via the PASSWORD_MASK_CHAR env var ...
+const DEFAULT_PASSWORD_MASK_CHAR: char = '•';
+
+// the keystroke echo line is off by default (the field is already masked via .secure(true));
+// opt in with PASSWORD_KEYSTROKE_ECHO=true for the extra per-keystroke feedback ...
+const DEFAULT_SHOW_KEYSTROKE_ECHO: bool = false;
+
+// read the configured mask char, falling back to DEFAULT_PASSWORD_MASK_CHAR ...
+fn load_password_mask_char() -> char {
+    std::env::var("PASSWORD_MASK_CHAR")
+        .ok()
+        .and_then(|value| value.chars().next())
+        .unwrap_or(DEFAULT_PASSWORD_MASK_CHAR)
+}
+
+// read whether the keystroke echo line should be shown, falling back to DEFAULT_SHOW_KEYSTROKE_ECHO ...
+fn load_show_keystroke_echo() -> bool {
+    std::env::var("PASSWORD_KEYSTROKE_ECHO")
+        .ok()
+        .map(|value| value.eq_ignore_ascii_case("true") || value == "1")
+        .unwrap_or(DEFAULT_SHOW_KEYSTROKE_ECHO)
+}
+
+// where the hot-reloadable theme lives, checked for changes on an interval ...
+const THEME_CONFIG_PATH: &str = "theme.toml";
+const THEME_RELOAD_INTERVAL_SECS: u64 = 2;
 
 // main entry point ...
 pub fn main() -> iced::Result {
     RustUI::run(Settings::default())
 }
 
+// the session returned by a successful login ...
+#[derive(Debug, Clone, Deserialize)]
+struct Session {
+    email: String,
+    token: String,
+}
+
 // define a new struct for RustUI
 struct RustUI {
     // define the main variables => used when asking an instance ...
     theme: Theme,
     page: Page,              // use this to track the pages
     login_field: LoginField, // use this to set email and password
+    logging_in: bool,        // true while the login request is in flight
+    session: Option<Session>,     // set once LoginSucceeded comes back
+    login_error: Option<String>,  // set once LoginFailed comes back
+    password_mask_char: char,     // character echoed per keystroke in password fields
+    show_keystroke_echo: bool,    // whether the keystroke echo line is rendered at all
+    theme_config: ThemeConfig,    // button/container styling, reloaded from THEME_CONFIG_PATH
+    theme_config_modified: Option<std::time::SystemTime>, // mtime of THEME_CONFIG_PATH as of the last reload
+    register_field: RegisterField,   // use this to set the sign-up form's fields
+    prompt_action: PromptAction,      // which form register_page is currently showing
+    register_error: Option<String>,  // validation error surfaced under the register form
+    register_success: Option<String>, // confirmation shown after a validated sign-up
+    history: Vec<Page>,      // every page visited, oldest first
+    history_index: usize,    // index into `history` for the page currently shown
 }
 
 // define a seperate struct for login field
@@ -27,37 +80,141 @@ struct LoginField {
     password: String,
 }
 
+// define a seperate struct for the sign-up form's fields
+#[derive(Debug, Clone, Default)]
+struct RegisterField {
+    username: String,
+    email: String,
+    password: String,
+    confirm_password: String,
+    device_name: String,
+}
+
 // define an enum for page => each var inside Page will create a new view/page
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Page {
     Login,
     Register,
 }
 
+impl Page {
+    // every page, in the order the menu bar lists them ...
+    const ALL: [Page; 2] = [Page::Login, Page::Register];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Page::Login => "Login",
+            Page::Register => "Register",
+        }
+    }
+}
+
+// which form register_page renders => toggled by the radio buttons at the top of the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PromptAction {
+    Login,
+    Register,
+}
+
 //define The message => these are similar to callback events/update triggers ...
 #[derive(Debug, Clone)]
 enum Message {
     ToggleTheme,                       // use to change dark/light theme
-    LoginSubmit,                       // use to submit email and password to console
-    Router(String),                    // change the page depending on route
+    LoginSubmit,                       // use to submit email and password to the login endpoint
+    LoginSucceeded(Session),           // the login request resolved with a session
+    LoginFailed(String),               // the login request resolved with an error message
+    Navigate(Page),                    // switch to a page, checked at compile time
+    NavigateBack,                      // step back in the navigation history
+    NavigateForward,                   // step forward in the navigation history
     LoginFieldChanged(String, String), // updates the input fields of email && password
+    ReloadTheme,                       // re-read THEME_CONFIG_PATH and repaint
+    RegisterUsernameChanged(String),        // updates the sign-up form's username field
+    RegisterEmailChanged(String),           // updates the sign-up form's email field
+    RegisterDeviceNameChanged(String),      // updates the sign-up form's device name field
+    RegisterPasswordChanged(String),        // updates the sign-up form's password field
+    RegisterConfirmPasswordChanged(String), // updates the sign-up form's confirm password field
+    PromptActionChanged(PromptAction),   // switches register_page between Login && Register
+    RegisterSubmit,                      // validate and submit the sign-up form
+}
+
+// client-side validation for the sign-up form => returns the first problem found, if any ...
+fn validate_register_field(field: &RegisterField) -> Result<(), String> {
+    if field.username.trim().is_empty() {
+        return Err("Username is required".to_string());
+    }
+    if field.email.trim().is_empty() {
+        return Err("Email address is required".to_string());
+    }
+    if !field.email.contains('@') || !field.email.contains('.') {
+        return Err("Email address is not valid".to_string());
+    }
+    if field.device_name.trim().is_empty() {
+        return Err("Device name is required".to_string());
+    }
+    if field.password.is_empty() {
+        return Err("Password is required".to_string());
+    }
+    if field.password != field.confirm_password {
+        return Err("Passwords do not match".to_string());
+    }
+    Ok(())
 }
 
-// implement a sandbox for RustUI
-impl Sandbox for RustUI {
+// post the email/password to the login endpoint and resolve into a Session ...
+async fn login_request(email: String, password: String) -> Result<Session, String> {
+    let endpoint = std::env::var("LOGIN_ENDPOINT").unwrap_or_else(|_| DEFAULT_LOGIN_ENDPOINT.to_string());
+
+    let response = reqwest::Client::new()
+        .post(endpoint)
+        .json(&serde_json::json!({ "email": email, "password": password }))
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("login failed with status {}", response.status()));
+    }
+
+    response
+        .json::<Session>()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+// implement the Application trait for RustUI => Sandbox has no async story, Application does ...
+impl Application for RustUI {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
 
     // define the app constructor
-    fn new() -> Self {
-        Self {
-            theme: Theme::Dark, // set default theme
-            page: Page::Login,  // set default page
-            login_field: LoginField {
-                // create the login field for email and password
-                email: String::new(),
-                password: String::new(),
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            Self {
+                theme: Theme::Dark, // set default theme
+                page: Page::Login,  // set default page
+                login_field: LoginField {
+                    // create the login field for email and password
+                    email: String::new(),
+                    password: String::new(),
+                },
+                logging_in: false,
+                session: None,
+                login_error: None,
+                password_mask_char: load_password_mask_char(),
+                show_keystroke_echo: load_show_keystroke_echo(),
+                theme_config: ThemeConfig::load(THEME_CONFIG_PATH),
+                theme_config_modified: ThemeConfig::modified(THEME_CONFIG_PATH),
+                register_field: RegisterField::default(),
+                prompt_action: PromptAction::Register,
+                register_error: None,
+                register_success: None,
+                history: vec![Page::Login],
+                history_index: 0,
             },
-        }
+            Command::none(),
+        )
     }
 
     // define the app title
@@ -70,59 +227,167 @@ impl Sandbox for RustUI {
         self.theme.clone() // return a copy of the theme
     }
 
+    // poll THEME_CONFIG_PATH on an interval so edits to the file show up without a recompile ...
+    fn subscription(&self) -> Subscription<Message> {
+        iced::time::every(std::time::Duration::from_secs(THEME_RELOAD_INTERVAL_SECS))
+            .map(|_| Message::ReloadTheme)
+    }
+
     // define the update method ...
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::ToggleTheme => {
                 self.theme = if self.theme == Theme::Light {
                     Theme::Dark
                 } else {
                     Theme::Light
-                }
+                };
+                Command::none()
             }
             Message::LoginFieldChanged(email, password) => {
                 self.login_field.email = email;
                 self.login_field.password = password;
+                Command::none()
             }
-            Message::LoginSubmit => {}
-            Message::Router(route) => {
-                if route == "login" {
-                    self.page = Page::Login;
-                } else if route == "register" {
-                    self.page = Page::Register;
+            Message::LoginSubmit => {
+                self.logging_in = true;
+                self.login_error = None;
+
+                let email = self.login_field.email.clone();
+                let password = self.login_field.password.clone();
+
+                Command::perform(login_request(email, password), |result| match result {
+                    Ok(session) => Message::LoginSucceeded(session),
+                    Err(error) => Message::LoginFailed(error),
+                })
+            }
+            Message::LoginSucceeded(session) => {
+                self.logging_in = false;
+                self.session = Some(session);
+                Command::none()
+            }
+            Message::LoginFailed(error) => {
+                self.logging_in = false;
+                self.login_error = Some(error);
+                Command::none()
+            }
+            Message::Navigate(page) => {
+                if self.history[self.history_index] != page {
+                    self.history.truncate(self.history_index + 1);
+                    self.history.push(page);
+                    self.history_index = self.history.len() - 1;
+                    self.page = page;
+                }
+                Command::none()
+            }
+            Message::NavigateBack => {
+                if self.history_index > 0 {
+                    self.history_index -= 1;
+                    self.page = self.history[self.history_index];
                 }
+                Command::none()
+            }
+            Message::NavigateForward => {
+                if self.history_index + 1 < self.history.len() {
+                    self.history_index += 1;
+                    self.page = self.history[self.history_index];
+                }
+                Command::none()
+            }
+            Message::ReloadTheme => {
+                let modified = ThemeConfig::modified(THEME_CONFIG_PATH);
+                if modified != self.theme_config_modified {
+                    self.theme_config = ThemeConfig::load(THEME_CONFIG_PATH);
+                    self.theme_config_modified = modified;
+                }
+                Command::none()
+            }
+            Message::RegisterUsernameChanged(username) => {
+                self.register_field.username = username;
+                Command::none()
+            }
+            Message::RegisterEmailChanged(email) => {
+                self.register_field.email = email;
+                Command::none()
+            }
+            Message::RegisterDeviceNameChanged(device_name) => {
+                self.register_field.device_name = device_name;
+                Command::none()
+            }
+            Message::RegisterPasswordChanged(password) => {
+                self.register_field.password = password;
+                Command::none()
+            }
+            Message::RegisterConfirmPasswordChanged(confirm_password) => {
+                self.register_field.confirm_password = confirm_password;
+                Command::none()
+            }
+            Message::PromptActionChanged(action) => {
+                if action == PromptAction::Register {
+                    // clear the post-submit confirmation once the user heads back to fill
+                    // out the form again ...
+                    self.register_success = None;
+                }
+                self.prompt_action = action;
+                Command::none()
+            }
+            Message::RegisterSubmit => {
+                match validate_register_field(&self.register_field) {
+                    Ok(()) => {
+                        self.register_error = None;
+                        self.register_success = Some(format!(
+                            "Account created for {} — sign in below.",
+                            self.register_field.username
+                        ));
+                        self.register_field = RegisterField::default();
+                        self.prompt_action = PromptAction::Login;
+                    }
+                    Err(error) => {
+                        self.register_error = Some(error);
+                        self.register_success = None;
+                    }
+                }
+                Command::none()
             }
         }
     }
 
     // define the view method => this is where the UI goes ...
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let content = match self.page {
-            Page::Login => log_in_page(&self.login_field),
-            Page::Register => register_page(),
+            Page::Login => log_in_page(
+                &self.login_field,
+                self.logging_in,
+                &self.login_error,
+                &self.session,
+                self.password_mask_char,
+                self.show_keystroke_echo,
+                &self.theme_config,
+            ),
+            Page::Register => register_page(
+                &self.login_field,
+                &self.register_field,
+                self.prompt_action,
+                &self.register_error,
+                &self.register_success,
+                self.password_mask_char,
+                self.show_keystroke_echo,
+                &self.theme_config,
+            ),
         };
 
         let wrapper = Column::new()
             .spacing(50)
             .width(Length::Fill)
             .align_items(Alignment::Center)
+            .push(menu_bar(
+                self.page,
+                self.history_index > 0,
+                self.history_index + 1 < self.history.len(),
+                &self.theme_config,
+            ))
             .push(content)
-            .push(match self.page {
-                Page::Login => page_footer(
-                    button("Page Two")
-                        .on_press(Message::Router("register".to_string()))
-                        .style(iced::theme::Button::Custom(Box::new(
-                            ButtonStyle::ThemeButton,
-                        ))),
-                ),
-                Page::Register => page_footer(
-                    button("Main Page - Login")
-                        .on_press(Message::Router("login".to_string()))
-                        .style(iced::theme::Button::Custom(Box::new(
-                            ButtonStyle::ThemeButton,
-                        ))),
-                ),
-            });
+            .push(page_footer(&self.theme_config));
 
         container(wrapper)
             .width(Length::Fill)
@@ -130,7 +395,9 @@ impl Sandbox for RustUI {
             .padding(Padding::from(20))
             .center_x()
             .center_y()
-            .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+            .style(iced::theme::Container::Custom(Box::new(container_style(
+                &self.theme_config,
+            ))))
             .into()
     }
 }
@@ -138,138 +405,489 @@ impl Sandbox for RustUI {
 // setup the different UI components
 
 // page footer ...
-fn page_footer(btn: Button<Message>) -> Container<Message> {
+fn page_footer(theme_config: &ThemeConfig) -> Container<'static, Message> {
     let footer = Row::new()
-        .push(button("Toggle Theme").on_press(Message::ToggleTheme).style(
-            iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)),
-        ))
-        .push(btn)
+        .push(
+            button("Toggle Theme")
+                .on_press(Message::ToggleTheme)
+                .style(iced::theme::Button::Custom(Box::new(theme_button_style(
+                    theme_config,
+                )))),
+        )
         .align_items(Alignment::Center)
         .spacing(10);
     container(footer).center_x().center_y()
 }
 
-// login page / first page ...
-fn log_in_page(login_field: &LoginField) -> Container<Message> {
-    let column = Column::new()
-        .push(text("Graphical User Interface - Iced"))
-        .push(
-            input_field("Email Address ...", &login_field.email)
-                .on_input(|email| Message::LoginFieldChanged(email, login_field.password.clone())),
-        )
-        .push(
-            input_field("Password ...", &login_field.password).on_input(|password| {
-                Message::LoginFieldChanged(login_field.email.clone(), password)
-            }),
-        )
-        .push(submit_btn("Login", Message::LoginSubmit))
-        .padding(Padding::from([50, 20]))
+// persistent top menu bar => lists every Page, highlights the active one, and exposes
+// back/forward through the navigation history ...
+fn menu_bar(page: Page, can_go_back: bool, can_go_forward: bool, theme_config: &ThemeConfig) -> Container<'static, Message> {
+    let mut bar = Row::new()
+        .push(nav_history_btn(
+            "< Back",
+            can_go_back.then_some(Message::NavigateBack),
+            theme_config,
+        ))
+        .push(nav_history_btn(
+            "Forward >",
+            can_go_forward.then_some(Message::NavigateForward),
+            theme_config,
+        ))
         .align_items(Alignment::Center)
-        .spacing(40);
+        .spacing(10);
+
+    for candidate in Page::ALL {
+        let style = if candidate == page {
+            standard_button_style(theme_config)
+        } else {
+            theme_button_style(theme_config)
+        };
+
+        bar = bar.push(
+            button(candidate.label())
+                .on_press(Message::Navigate(candidate))
+                .style(iced::theme::Button::Custom(Box::new(style))),
+        );
+    }
+
+    container(bar)
+        .width(Length::Fill)
+        .padding(Padding::from(10))
+        .center_x()
+}
+
+// small, unsized button used for the back/forward navigation history controls ...
+fn nav_history_btn(
+    label: &'static str,
+    event: Option<Message>,
+    theme_config: &ThemeConfig,
+) -> Button<'static, Message> {
+    let btn = button(label).style(iced::theme::Button::Custom(Box::new(theme_button_style(
+        theme_config,
+    ))));
+
+    match event {
+        Some(event) => btn.on_press(event),
+        None => btn,
+    }
+}
+
+// login page / first page ...
+fn log_in_page<'a>(
+    login_field: &'a LoginField,
+    logging_in: bool,
+    login_error: &'a Option<String>,
+    session: &'a Option<Session>,
+    password_mask_char: char,
+    show_keystroke_echo: bool,
+    theme_config: &ThemeConfig,
+) -> Container<'a, Message> {
+    let column = if let Some(session) = session {
+        Column::new()
+            .push(text("Graphical User Interface - Iced"))
+            .push(text(format!("Signed in as {}", session.email)).size(24))
+            .push(text(format!("Session token: {}", session.token)).size(14))
+            .padding(Padding::from([50, 20]))
+            .align_items(Alignment::Center)
+            .spacing(40)
+    } else {
+        let mut column = Column::new()
+            .push(text("Graphical User Interface - Iced"))
+            .push(
+                input_field("Email Address ...", &login_field.email, false).on_input(|email| {
+                    Message::LoginFieldChanged(email, login_field.password.clone())
+                }),
+            )
+            .push(
+                password_field("Password ...", &login_field.password).on_input(|password| {
+                    Message::LoginFieldChanged(login_field.email.clone(), password)
+                }),
+            )
+            .padding(Padding::from([50, 20]))
+            .align_items(Alignment::Center)
+            .spacing(40);
+
+        if show_keystroke_echo {
+            column = column.push(text(keystroke_echo(&login_field.password, password_mask_char)).size(16));
+        }
+
+        column = column.push(submit_btn(
+            if logging_in { "Logging in ..." } else { "Login" },
+            if logging_in { None } else { Some(Message::LoginSubmit) },
+            theme_config,
+        ));
+
+        if let Some(error) = login_error {
+            column = column.push(text(error).size(16));
+        }
+
+        column
+    };
+
     container(column)
         .padding(Padding::from(20))
-        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+        .style(iced::theme::Container::Custom(Box::new(container_style(
+            theme_config,
+        ))))
 }
 
 // register page / second page ...
-fn register_page() -> Container<'static, Message> {
-    let column = Column::new().push(text("Page two").size(64));
+#[allow(clippy::too_many_arguments)]
+fn register_page<'a>(
+    login_field: &'a LoginField,
+    register_field: &'a RegisterField,
+    prompt_action: PromptAction,
+    register_error: &'a Option<String>,
+    register_success: &'a Option<String>,
+    password_mask_char: char,
+    show_keystroke_echo: bool,
+    theme_config: &ThemeConfig,
+) -> Container<'a, Message> {
+    let prompt_toggle = Row::new()
+        .push(radio(
+            "Login",
+            PromptAction::Login,
+            Some(prompt_action),
+            Message::PromptActionChanged,
+        ))
+        .push(radio(
+            "Register",
+            PromptAction::Register,
+            Some(prompt_action),
+            Message::PromptActionChanged,
+        ))
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let form: Element<Message> = match prompt_action {
+        PromptAction::Login => {
+            let mut login_column = Column::new()
+                .push(
+                    input_field("Email Address ...", &login_field.email, false).on_input(
+                        |email| Message::LoginFieldChanged(email, login_field.password.clone()),
+                    ),
+                )
+                .push(
+                    password_field("Password ...", &login_field.password).on_input(|password| {
+                        Message::LoginFieldChanged(login_field.email.clone(), password)
+                    }),
+                )
+                .align_items(Alignment::Center)
+                .spacing(20);
+
+            if let Some(success) = register_success {
+                login_column = login_column.push(text(success).size(16));
+            }
+
+            if show_keystroke_echo {
+                login_column = login_column.push(
+                    text(keystroke_echo(&login_field.password, password_mask_char)).size(16),
+                );
+            }
+
+            login_column
+                .push(submit_btn("Login", Some(Message::LoginSubmit), theme_config))
+                .into()
+        }
+        PromptAction::Register => Column::new()
+            .push(
+                input_field("Username ...", &register_field.username, false)
+                    .on_input(Message::RegisterUsernameChanged),
+            )
+            .push(
+                input_field("Email Address ...", &register_field.email, false)
+                    .on_input(Message::RegisterEmailChanged),
+            )
+            .push(
+                input_field("Device Name ...", &register_field.device_name, false)
+                    .on_input(Message::RegisterDeviceNameChanged),
+            )
+            .push(
+                password_field("Password ...", &register_field.password)
+                    .on_input(Message::RegisterPasswordChanged),
+            )
+            .push(
+                password_field("Confirm Password ...", &register_field.confirm_password)
+                    .on_input(Message::RegisterConfirmPasswordChanged),
+            )
+            .push(submit_btn(
+                "Register",
+                Some(Message::RegisterSubmit),
+                theme_config,
+            ))
+            .align_items(Alignment::Center)
+            .spacing(20)
+            .into(),
+    };
+
+    let mut column = Column::new()
+        .push(prompt_toggle)
+        .push(text("Create an account").size(32))
+        .push(form)
+        .padding(Padding::from([50, 20]))
+        .align_items(Alignment::Center)
+        .spacing(40);
+
+    if let Some(error) = register_error {
+        column = column.push(text(error).size(16));
+    }
 
     container(column)
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .center_x()
-        .center_y()
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(container_style(
+            theme_config,
+        ))))
 }
 
 // input field ...
-fn input_field(_placeholder: &str, _value: &str) -> TextInput<'static, Message> {
+fn input_field(_placeholder: &str, _value: &str, secure: bool) -> TextInput<'static, Message> {
     TextInput::new(_placeholder, _value)
         .width(Length::Fixed(500.00))
         .padding(Padding::from(10.0))
         .line_height(text::LineHeight::Relative(1.75))
+        .secure(secure)
+}
+
+// password field => same as input_field but always rendered with characters masked as dots ...
+fn password_field(_placeholder: &str, _value: &str) -> TextInput<'static, Message> {
+    input_field(_placeholder, _value, true)
+}
+
+// builds the per-keystroke redaction feedback line shown under the password field,
+// echoing `mask_char` once for every character currently typed ...
+fn keystroke_echo(value: &str, mask_char: char) -> String {
+    mask_char.to_string().repeat(value.chars().count())
 }
 
-// submit button ...
-fn submit_btn(name: &str, event: Message) -> Button<Message> {
-    Button::new(
+// submit button => pass None for event to render it disabled (e.g. while logging in) ...
+fn submit_btn(name: &str, event: Option<Message>, theme_config: &ThemeConfig) -> Button<'static, Message> {
+    let btn = Button::new(
         text(name)
             .horizontal_alignment(Horizontal::Center)
             .vertical_alignment(Vertical::Center)
             .size(21),
     )
-    .on_press(event)
     .width(Length::Fixed(500.00))
     .height(Length::Fixed(45.00))
     // define the custom style
-    .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard)))
+    .style(iced::theme::Button::Custom(Box::new(standard_button_style(
+        theme_config,
+    ))));
+
+    match event {
+        Some(event) => btn.on_press(event),
+        None => btn,
+    }
 }
 
 // define a few structs for styling ...
 
-// button styling
-enum ButtonStyle {
-    Standard,
-    ThemeButton,
+// the hot-reloadable theme, parsed from THEME_CONFIG_PATH => falls back to the built-in
+// defaults below whenever the file is missing or fails to parse ...
+// every table below is #[serde(default)], down to its keys: a `theme.toml` that only
+// overrides a handful of keys (or has a typo in one of them) should still pick up the rest
+// of these defaults instead of discarding the whole table ...
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ThemeConfig {
+    #[serde(default)]
+    button: ButtonThemeConfig,
+    #[serde(default)]
+    container: ContainerThemeConfig,
 }
 
-impl button::StyleSheet for ButtonStyle {
-    type Style = Theme;
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct ButtonThemeConfig {
+    standard_background: String,         // hex color for the Standard button's background
+    standard_text_color: String,         // hex color for the Standard button's text
+    border_radius: f32,                  // Standard button corner radius
+    shadow_color: String,                // Standard button shadow color
+    shadow_offset_x: f32,
+    shadow_offset_y: f32,
+    shadow_blur: f32,
+    theme_button_light_text_color: String, // ThemeButton text color on Theme::Light
+    theme_button_dark_text_color: String,  // ThemeButton text color otherwise
+}
 
-    // define the active trait => the default button
-    fn active(&self, theme: &Self::Style) -> button::Appearance {
-        button::Appearance {
-            background: Some(Background::Color(match self {
-                Self::Standard => iced::Color::from_rgb(0.059, 0.463, 0.702),
-                Self::ThemeButton => iced::Color::default(),
-            })),
-            border: match self {
-                Self::Standard => Border::with_radius(5),
-                Self::ThemeButton => Border::default(),
-            },
-            shadow: match self {
-                Self::Standard => Shadow {
-                    color: iced::Color::BLACK,
-                    offset: Vector::new(0.0, 4.0),
-                    blur_radius: 20.0,
-                },
-                Self::ThemeButton => Shadow::default(),
-            },
-            text_color: {
-                if theme == &Theme::Light {
-                    match self {
-                        Self::Standard => iced::Color::WHITE,
-                        Self::ThemeButton => iced::Color::BLACK,
-                    }
-                } else {
-                    match self {
-                        Self::Standard => iced::Color::WHITE,
-                        Self::ThemeButton => iced::Color::WHITE,
-                    }
-                }
-            },
-            ..Default::default()
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct ContainerThemeConfig {
+    border_radius: f32,
+    shadow_color: String,
+    shadow_offset_x: f32,
+    shadow_offset_y: f32,
+    shadow_blur: f32,
+}
+
+impl Default for ButtonThemeConfig {
+    fn default() -> Self {
+        Self {
+            standard_background: "#0F76B3".to_string(),
+            standard_text_color: "#FFFFFF".to_string(),
+            border_radius: 5.0,
+            shadow_color: "#000000".to_string(),
+            shadow_offset_x: 0.0,
+            shadow_offset_y: 4.0,
+            shadow_blur: 20.0,
+            theme_button_light_text_color: "#000000".to_string(),
+            theme_button_dark_text_color: "#FFFFFF".to_string(),
+        }
+    }
+}
+
+impl Default for ContainerThemeConfig {
+    fn default() -> Self {
+        Self {
+            border_radius: 5.0,
+            shadow_color: "#000000".to_string(),
+            shadow_offset_x: 0.0,
+            shadow_offset_y: 2.0,
+            shadow_blur: 40.0,
         }
     }
 }
 
-// define the container style, similar to the button style ...
-struct ContainerStyle;
+impl ThemeConfig {
+    // read and parse `path`, falling back to Self::default() on any IO/parse error ...
+    fn load(path: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
 
-impl container::StyleSheet for ContainerStyle {
+    // mtime of `path`, if it can be stat'd => used to skip reparsing the file on every
+    // subscription tick when it hasn't actually changed since the last reload ...
+    fn modified(path: &str) -> Option<std::time::SystemTime> {
+        std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+    }
+}
+
+// parse a "#RRGGBB" hex string into an iced Color => each channel that is missing or not valid
+// hex falls back to 0, so a wholly bad string gives black and a partly bad one keeps its good channels ...
+fn hex_to_color(hex: &str) -> iced::Color {
+    let hex = hex.trim_start_matches('#');
+    let r = u8::from_str_radix(hex.get(0..2).unwrap_or("00"), 16).unwrap_or(0);
+    let g = u8::from_str_radix(hex.get(2..4).unwrap_or("00"), 16).unwrap_or(0);
+    let b = u8::from_str_radix(hex.get(4..6).unwrap_or("00"), 16).unwrap_or(0);
+    iced::Color::from_rgb8(r, g, b)
+}
+
+// the status a button style closure is asked to render for ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ButtonStatus {
+    Active,
+    Hovered,
+    Pressed,
+    Disabled,
+}
+
+// adapts a plain closure into a button::StyleSheet, so callers don't need an enum + impl
+// block per look => just a closure of the form `|theme, status| -> button::Appearance` ...
+type ButtonStyleClosure = Box<dyn Fn(&Theme, ButtonStatus) -> button::Appearance>;
+struct ButtonStyleFn(ButtonStyleClosure);
+
+impl button::StyleSheet for ButtonStyleFn {
     type Style = Theme;
 
-    //define the active trait as needed ...
-    fn appearance(&self, _theme: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Default::default(),
-            border: Border::with_radius(5),
-            background: None,
-            shadow: Shadow {
-                color: iced::Color::BLACK,
-                offset: Vector::new(0.0, 2.0),
-                blur_radius: 40.0,
-            },
-        }
+    fn active(&self, style: &Self::Style) -> button::Appearance {
+        (self.0)(style, ButtonStatus::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> button::Appearance {
+        (self.0)(style, ButtonStatus::Hovered)
     }
+
+    fn pressed(&self, style: &Self::Style) -> button::Appearance {
+        (self.0)(style, ButtonStatus::Pressed)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> button::Appearance {
+        (self.0)(style, ButtonStatus::Disabled)
+    }
+}
+
+// wraps any closure as a button style => lets call sites compose one-off tweaks inline
+// (e.g. a danger-colored submit button) without adding a new enum variant ...
+fn button_style(f: impl Fn(&Theme, ButtonStatus) -> button::Appearance + 'static) -> ButtonStyleFn {
+    ButtonStyleFn(Box::new(f))
+}
+
+// standard (filled, primary) button style, sourced from the theme config => the hex strings
+// are resolved to iced::Colors up front so the closure only ever captures Copy values, instead
+// of cloning the whole (string-heavy) ThemeConfig into every button built on every redraw ...
+fn standard_button_style(config: &ThemeConfig) -> ButtonStyleFn {
+    let background = hex_to_color(&config.button.standard_background);
+    let text_color = hex_to_color(&config.button.standard_text_color);
+    let border_radius = config.button.border_radius;
+    let shadow_color = hex_to_color(&config.button.shadow_color);
+    let shadow_offset = Vector::new(config.button.shadow_offset_x, config.button.shadow_offset_y);
+    let shadow_blur = config.button.shadow_blur;
+
+    button_style(move |_theme, status| button::Appearance {
+        background: Some(Background::Color(background)),
+        border: Border::with_radius(border_radius),
+        shadow: if status == ButtonStatus::Disabled {
+            Shadow::default()
+        } else {
+            Shadow {
+                color: shadow_color,
+                offset: shadow_offset,
+                blur_radius: shadow_blur,
+            }
+        },
+        text_color,
+        ..Default::default()
+    })
+}
+
+// transparent, text-only button style used by the menu bar and footer, sourced from the
+// theme config => centralizes the light/dark text-color logic in one place ...
+fn theme_button_style(config: &ThemeConfig) -> ButtonStyleFn {
+    let light_text_color = hex_to_color(&config.button.theme_button_light_text_color);
+    let dark_text_color = hex_to_color(&config.button.theme_button_dark_text_color);
+
+    button_style(move |theme, _status| button::Appearance {
+        background: Some(Background::Color(iced::Color::default())),
+        border: Border::default(),
+        shadow: Shadow::default(),
+        text_color: if theme == &Theme::Light {
+            light_text_color
+        } else {
+            dark_text_color
+        },
+        ..Default::default()
+    })
+}
+
+// adapts a plain closure into a container::StyleSheet, mirroring ButtonStyleFn ...
+type ContainerStyleClosure = Box<dyn Fn(&Theme) -> container::Appearance>;
+struct ContainerStyleFn(ContainerStyleClosure);
+
+impl container::StyleSheet for ContainerStyleFn {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        (self.0)(style)
+    }
+}
+
+// the container look used throughout the app, sourced from the theme config => resolved to
+// Copy values up front, same reasoning as standard_button_style/theme_button_style above ...
+fn container_style(config: &ThemeConfig) -> ContainerStyleFn {
+    let border_radius = config.container.border_radius;
+    let shadow_color = hex_to_color(&config.container.shadow_color);
+    let shadow_offset = Vector::new(config.container.shadow_offset_x, config.container.shadow_offset_y);
+    let shadow_blur = config.container.shadow_blur;
+
+    ContainerStyleFn(Box::new(move |_theme| container::Appearance {
+        text_color: Default::default(),
+        border: Border::with_radius(border_radius),
+        background: None,
+        shadow: Shadow {
+            color: shadow_color,
+            offset: shadow_offset,
+            blur_radius: shadow_blur,
+        },
+    }))
 }